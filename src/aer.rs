@@ -131,6 +131,33 @@ where
         }
     }
 
+    /// Create a new AER vector
+    ///
+    /// # Arguments
+    /// - `azimuth` in gradians (0 to 400) measured clockwise from north
+    /// - `elevation` in gradians (-100 to 100)
+    /// - `range` in meters
+    ///
+    /// # Panics
+    /// This will panic if `azimuth` or `elevation` are not within the required bounds
+    pub fn from_gon_and_meters(azimuth: N, elevation: N, range: N) -> AER<N> {
+        assert!(
+            N::from_f64(0.0).unwrap() <= azimuth && azimuth <= N::from_f64(400.0).unwrap(),
+            "Azimuth must be in the range [0, 400]"
+        );
+        assert!(
+            elevation.abs() <= N::from_f64(100.0).unwrap(),
+            "Elevation must be in the range [-100, 100]"
+        );
+
+        AER {
+            azimuth: N::from_f64(f64::from(azimuth) * core::f64::consts::TAU / 400.0).unwrap(),
+            elevation: N::from_f64(f64::from(elevation) * core::f64::consts::TAU / 400.0)
+                .unwrap(),
+            range,
+        }
+    }
+
     /// Get azimuth in degrees
     pub fn azimuth_degrees(&self) -> N {
         N::from_f64(f64::from(self.azimuth).to_degrees()).unwrap()
@@ -153,7 +180,75 @@ where
 
     /// Get range in meters
     pub fn range(&self) -> N {
-        N::from_f64(f64::from(self.range).to_degrees()).unwrap()
+        self.range
+    }
+
+    /// Get azimuth in gradians (gon)
+    pub fn azimuth_gon(&self) -> N {
+        N::from_f64(f64::from(self.azimuth) * 400.0 / core::f64::consts::TAU).unwrap()
+    }
+
+    /// Get elevation in gradians (gon)
+    pub fn elevation_gon(&self) -> N {
+        N::from_f64(f64::from(self.elevation) * 400.0 / core::f64::consts::TAU).unwrap()
+    }
+
+    /// Convert to equatorial hour-angle / declination coordinates
+    ///
+    /// # Arguments
+    /// - `latitude` observer geodetic latitude, in radians
+    ///
+    /// # Returns
+    /// `(hour_angle, declination)` in radians
+    pub fn to_hadec(&self, latitude: N) -> (N, N) {
+        let sp = latitude.sin();
+        let cp = latitude.cos();
+        let az = self.azimuth;
+        let el = self.elevation;
+
+        let x = -az.cos() * el.cos() * sp + el.sin() * cp;
+        let y = -az.sin() * el.cos();
+        let z = az.cos() * el.cos() * cp + el.sin() * sp;
+
+        let r = N::from_f64(f64::sqrt(f64::from(x).powi(2) + f64::from(y).powi(2))).unwrap();
+        let hour_angle = if r == N::from_f64(0.0).unwrap() {
+            N::from_f64(0.0).unwrap()
+        } else {
+            y.atan2(x)
+        };
+        let declination = z.atan2(r);
+
+        (hour_angle, declination)
+    }
+
+    /// Create an AER vector from equatorial hour-angle / declination
+    ///
+    /// # Arguments
+    /// - `hour_angle` in radians
+    /// - `declination` in radians
+    /// - `latitude` observer geodetic latitude, in radians
+    /// - `range` in meters
+    pub fn from_hadec(hour_angle: N, declination: N, latitude: N, range: N) -> AER<N> {
+        let sp = latitude.sin();
+        let cp = latitude.cos();
+        let sd = declination.sin();
+        let cd = declination.cos();
+        let ch = hour_angle.cos();
+
+        let elevation = (sp * sd + cp * cd * ch).asin();
+
+        let x = -sp * cd * ch + cp * sd;
+        let y = -cd * hour_angle.sin();
+        let mut azimuth = y.atan2(x);
+        if azimuth.is_negative() {
+            azimuth += N::from_f64(core::f64::consts::TAU).unwrap();
+        }
+
+        AER {
+            azimuth,
+            elevation,
+            range,
+        }
     }
 }
 
@@ -189,6 +284,96 @@ impl<N: RealFieldCopy + Neg<Output = N>> From<AER<N>> for ENU<N> {
     }
 }
 
+/// Time derivatives of azimuth, elevation and range
+///
+/// This is what a tracking mount needs in order to slew smoothly, or what's
+/// needed to compute the Doppler shift of a received signal, given a
+/// relative position and relative velocity in `ENU`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct AERRate<N> {
+    // Represented as radians/s
+    azimuth_rate: N,
+    // Represented as radians/s
+    elevation_rate: N,
+    range_rate: N,
+}
+
+impl<N: RealFieldCopy> AERRate<N>
+where
+    f64: From<N>,
+{
+    /// Get azimuth rate in degrees/s
+    pub fn azimuth_rate_degrees(&self) -> N {
+        N::from_f64(f64::from(self.azimuth_rate).to_degrees()).unwrap()
+    }
+
+    /// Get elevation rate in degrees/s
+    pub fn elevation_rate_degrees(&self) -> N {
+        N::from_f64(f64::from(self.elevation_rate).to_degrees()).unwrap()
+    }
+
+    /// Get azimuth rate in radians/s
+    pub fn azimuth_rate_radians(&self) -> N {
+        self.azimuth_rate
+    }
+
+    /// Get elevation rate in radians/s
+    pub fn elevation_rate_radians(&self) -> N {
+        self.elevation_rate
+    }
+
+    /// Get range rate in meters/s (positive when the range is increasing,
+    /// negative when the target is closing)
+    pub fn range_rate(&self) -> N {
+        self.range_rate
+    }
+}
+
+impl<N: RealFieldCopy> From<(ENU<N>, ENU<N>)> for AERRate<N>
+where
+    f64: From<N>,
+{
+    /// Compute azimuth-rate, elevation-rate and range-rate from a relative
+    /// position and relative velocity, both expressed in `ENU`
+    fn from((position, velocity): (ENU<N>, ENU<N>)) -> Self {
+        let e = f64::from(position.east());
+        let n = f64::from(position.north());
+        let u = f64::from(position.up());
+        let ve = f64::from(velocity.east());
+        let vn = f64::from(velocity.north());
+        let vu = f64::from(velocity.up());
+
+        let horizontal_sq = e.powi(2) + n.powi(2);
+        let range = f64::from(position.norm());
+        let along = e * ve + n * vn;
+
+        let (azimuth_rate, elevation_rate, range_rate) = if range == 0.0 {
+            // Observer and target coincide: range, azimuth and elevation are
+            // all undefined, so we report no motion rather than dividing by
+            // zero.
+            (0.0, 0.0, 0.0)
+        } else if horizontal_sq == 0.0 {
+            // Target is at zenith/nadir: azimuth is undefined, so we report
+            // no azimuth motion.
+            (0.0, 0.0, (along + u * vu) / range)
+        } else {
+            let horizontal = f64::sqrt(horizontal_sq);
+            (
+                (ve * n - vn * e) / horizontal_sq,
+                (vu * horizontal_sq - u * along) / (range.powi(2) * horizontal),
+                (along + u * vu) / range,
+            )
+        };
+
+        AERRate {
+            azimuth_rate: N::from_f64(azimuth_rate).unwrap(),
+            elevation_rate: N::from_f64(elevation_rate).unwrap(),
+            range_rate: N::from_f64(range_rate).unwrap(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -289,6 +474,58 @@ mod tests {
         close(aer.range(), double_conversion.range(), 0.0001);
     }
 
+    #[test]
+    fn hadec_roundtrip_is_identity() {
+        let aer = AER::from_degrees_and_meters(123.0, 35.0, 500.0);
+        let latitude = 51.0_f64.to_radians();
+
+        let (hour_angle, declination) = aer.to_hadec(latitude);
+        let roundtrip = AER::from_hadec(hour_angle, declination, latitude, aer.range());
+
+        close(aer.azimuth_radians(), roundtrip.azimuth_radians(), 0.0001);
+        close(aer.elevation_radians(), roundtrip.elevation_radians(), 0.0001);
+        close(aer.range(), roundtrip.range(), 0.0001);
+    }
+
+    #[test]
+    fn aer_rate_overhead_pass_has_azimuth_rate_zero_at_zenith() {
+        // Directly overhead, closing radially: range-rate should equal the
+        // vertical speed and azimuth-rate is undefined (reported as zero).
+        let position = ENU::new(0.0, 0.0, 500.0);
+        let velocity = ENU::new(0.0, 0.0, -10.0);
+
+        let rate = AERRate::from((position, velocity));
+        close(rate.range_rate(), -10.0, 0.0001);
+        close(rate.azimuth_rate_radians(), 0.0, 0.0001);
+    }
+
+    #[test]
+    fn aer_rate_coincident_points_reports_no_motion() {
+        // Observer and target at the same point: range, azimuth and
+        // elevation are all undefined, so every rate must come out as a
+        // well-defined zero rather than NaN from a division by zero.
+        let position = ENU::new(0.0, 0.0, 0.0);
+        let velocity = ENU::new(1.0, 2.0, 3.0);
+
+        let rate = AERRate::from((position, velocity));
+        close(rate.azimuth_rate_radians(), 0.0, 0.0001);
+        close(rate.elevation_rate_radians(), 0.0, 0.0001);
+        close(rate.range_rate(), 0.0, 0.0001);
+    }
+
+    #[test]
+    fn aer_rate_pure_radial_motion_has_no_angular_rate() {
+        // Moving straight away from the observer along the current
+        // line-of-sight shouldn't change azimuth or elevation.
+        let position = ENU::new(3.0, 4.0, 0.0);
+        let velocity = ENU::new(6.0, 8.0, 0.0);
+
+        let rate = AERRate::from((position, velocity));
+        close(rate.azimuth_rate_radians(), 0.0, 0.0001);
+        close(rate.elevation_rate_radians(), 0.0, 0.0001);
+        close(rate.range_rate(), 10.0, 0.0001);
+    }
+
     #[test]
     fn known_good_enu_conversion() {
         //values taken from matlab aer2enu: https://mathworks.com/help/map/ref/aer2enu.html
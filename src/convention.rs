@@ -0,0 +1,98 @@
+use crate::utils::RealFieldCopy;
+use crate::{AER, ENU, NED};
+use core::ops::Neg;
+
+/// Angular unit used to interpret the azimuth/elevation values passed to
+/// [`adapt`]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum AngleUnit {
+    Radians,
+    Degrees,
+    Gon,
+}
+
+/// A local coordinate convention that telemetry may be expressed in
+///
+/// `Aer` pairs with the crate's native `AER`/`ENU` relationship: the three
+/// values are azimuth and elevation in the given `AngleUnit`, and range in
+/// meters. `Ned`
+/// pairs with `NED`: the three values are north, east and down, in meters,
+/// as published by most flight controllers.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Convention {
+    Aer(AngleUnit),
+    Ned,
+}
+
+/// Convert a local 3-tuple from one [`Convention`] to another without
+/// writing the trigonometry by hand
+///
+/// # Arguments
+/// - `values` the three telemetry values, interpreted per `from`
+/// - `from` the convention `values` is expressed in
+/// - `to` the convention the result should be expressed in
+pub fn adapt<N>(values: (N, N, N), from: Convention, to: Convention) -> (N, N, N)
+where
+    N: RealFieldCopy + Neg<Output = N>,
+    f64: From<N>,
+{
+    let (a, b, c) = values;
+    let enu: ENU<N> = match from {
+        Convention::Aer(unit) => {
+            let aer = match unit {
+                AngleUnit::Radians => AER::from_radians_and_meters(a, b, c),
+                AngleUnit::Degrees => AER::from_degrees_and_meters(a, b, c),
+                AngleUnit::Gon => AER::from_gon_and_meters(a, b, c),
+            };
+            ENU::from(aer)
+        }
+        Convention::Ned => ENU::from(NED::new(a, b, c)),
+    };
+
+    match to {
+        Convention::Aer(unit) => {
+            let aer = AER::from(enu);
+            match unit {
+                AngleUnit::Radians => (aer.azimuth_radians(), aer.elevation_radians(), aer.range()),
+                AngleUnit::Degrees => (aer.azimuth_degrees(), aer.elevation_degrees(), aer.range()),
+                AngleUnit::Gon => (aer.azimuth_gon(), aer.elevation_gon(), aer.range()),
+            }
+        }
+        Convention::Ned => {
+            let ned = NED::from(enu);
+            (ned.north(), ned.east(), ned.down())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use assert::close;
+
+    #[test]
+    fn aer_degrees_round_trips_through_ned() {
+        let original = (45.0, 10.0, 100.0);
+        let ned = adapt(original, Convention::Aer(AngleUnit::Degrees), Convention::Ned);
+        let back = adapt(ned, Convention::Ned, Convention::Aer(AngleUnit::Degrees));
+
+        close(original.0, back.0, 0.0001);
+        close(original.1, back.1, 0.0001);
+        close(original.2, back.2, 0.0001);
+    }
+
+    #[test]
+    fn gon_matches_degrees() {
+        let degrees = (90.0, 45.0, 100.0);
+        let gon = adapt(
+            degrees,
+            Convention::Aer(AngleUnit::Degrees),
+            Convention::Aer(AngleUnit::Gon),
+        );
+
+        close(gon.0, 100.0, 0.0001);
+        close(gon.1, 50.0, 0.0001);
+        close(gon.2, 100.0, 0.0001);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::utils::RealFieldCopy;
+use crate::ENU;
+use core::ops::Neg;
+
+/// Local north-east-down (NED) Cartesian coordinates
+///
+/// This is the same tangent-plane frame as `ENU`, but with the first two
+/// axes swapped and the vertical axis flipped: `north` takes the place of
+/// `east` as the first component, `east` takes the place of `north` as the
+/// second, and `down` is the negative of `up`. This is the axis convention
+/// many flight controllers and autopilots publish telemetry in.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct NED<N> {
+    north: N,
+    east: N,
+    down: N,
+}
+
+impl<N: RealFieldCopy> NED<N> {
+    /// Create a new NED vector
+    pub fn new(north: N, east: N, down: N) -> NED<N> {
+        NED { north, east, down }
+    }
+
+    /// Get north component in meters
+    pub fn north(&self) -> N {
+        self.north
+    }
+
+    /// Get east component in meters
+    pub fn east(&self) -> N {
+        self.east
+    }
+
+    /// Get down component in meters
+    pub fn down(&self) -> N {
+        self.down
+    }
+}
+
+impl<N: RealFieldCopy + Neg<Output = N>> From<ENU<N>> for NED<N> {
+    fn from(enu: ENU<N>) -> Self {
+        NED {
+            north: enu.north(),
+            east: enu.east(),
+            down: -enu.up(),
+        }
+    }
+}
+
+impl<N: RealFieldCopy + Neg<Output = N>> From<NED<N>> for ENU<N> {
+    fn from(ned: NED<N>) -> Self {
+        ENU::new(ned.east, ned.north, -ned.down)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use assert::close;
+
+    #[test]
+    fn double_conversion_is_identity() {
+        let ned = NED::new(1.0, 2.0, 3.0);
+        let double_conversion = NED::from(ENU::from(ned));
+        close(ned.north(), double_conversion.north(), 0.0001);
+        close(ned.east(), double_conversion.east(), 0.0001);
+        close(ned.down(), double_conversion.down(), 0.0001);
+    }
+
+    #[test]
+    fn axes_are_swapped_and_down_is_negative_up() {
+        let enu = ENU::new(1.0, 2.0, 3.0);
+        let ned = NED::from(enu);
+        close(ned.north(), enu.north(), 0.0001);
+        close(ned.east(), enu.east(), 0.0001);
+        close(ned.down(), -enu.up(), 0.0001);
+    }
+}
@@ -0,0 +1,357 @@
+use crate::utils::RealFieldCopy;
+use crate::{AER, ENU};
+
+/// WGS84 semi-major axis, in meters
+const WGS84_A: f64 = 6_378_137.0;
+/// WGS84 flattening
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// Geodetic position on the WGS84 reference ellipsoid
+///
+/// This struct represents a position given in terms of geodetic latitude,
+/// longitude and altitude above the WGS84 ellipsoid.
+///
+/// Note: latitude and longitude are stored internally in radians
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct WGS84<N> {
+    latitude: N,
+    longitude: N,
+    altitude: N,
+}
+
+impl<N: RealFieldCopy> WGS84<N>
+where
+    f64: From<N>,
+{
+    /// Create a new WGS84 position
+    ///
+    /// # Arguments
+    /// - `latitude` in degrees (-90 to 90)
+    /// - `longitude` in degrees
+    /// - `altitude` in meters above the WGS84 ellipsoid
+    ///
+    /// # Panics
+    /// This will panic if `latitude` is not within the required bounds
+    pub fn from_degrees_and_meters(latitude: N, longitude: N, altitude: N) -> WGS84<N> {
+        assert!(
+            latitude.abs() <= N::from_f64(90.0).unwrap(),
+            "Latitude must be in the range [-90, 90]"
+        );
+
+        WGS84 {
+            latitude: N::from_f64(f64::from(latitude).to_radians()).unwrap(),
+            longitude: N::from_f64(f64::from(longitude).to_radians()).unwrap(),
+            altitude,
+        }
+    }
+
+    /// Create a new WGS84 position
+    ///
+    /// # Arguments
+    /// - `latitude` in radians (-tau/4 to tau/4)
+    /// - `longitude` in radians
+    /// - `altitude` in meters above the WGS84 ellipsoid
+    ///
+    /// # Panics
+    /// This will panic if `latitude` is not within the required bounds
+    pub fn from_radians_and_meters(latitude: N, longitude: N, altitude: N) -> WGS84<N> {
+        assert!(
+            latitude.abs() <= N::from_f64(core::f64::consts::FRAC_PI_2).unwrap(),
+            "Latitude must be in the range [-tau/4, tau/4]"
+        );
+
+        WGS84 {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Get latitude in degrees
+    pub fn latitude_degrees(&self) -> N {
+        N::from_f64(f64::from(self.latitude).to_degrees()).unwrap()
+    }
+
+    /// Get longitude in degrees
+    pub fn longitude_degrees(&self) -> N {
+        N::from_f64(f64::from(self.longitude).to_degrees()).unwrap()
+    }
+
+    /// Get latitude in radians
+    pub fn latitude_radians(&self) -> N {
+        self.latitude
+    }
+
+    /// Get longitude in radians
+    pub fn longitude_radians(&self) -> N {
+        self.longitude
+    }
+
+    /// Get altitude in meters above the WGS84 ellipsoid
+    pub fn altitude(&self) -> N {
+        self.altitude
+    }
+
+    /// Convert to Earth-centered, Earth-fixed Cartesian coordinates
+    /// `(x, y, z)` in meters
+    fn to_ecef(&self) -> (N, N, N) {
+        let lat = f64::from(self.latitude);
+        let lon = f64::from(self.longitude);
+        let alt = f64::from(self.altitude);
+
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let sin_lat = lat.sin();
+        let cos_lat = lat.cos();
+        let n = WGS84_A / f64::sqrt(1.0 - e2 * sin_lat.powi(2));
+
+        let x = (n + alt) * cos_lat * lon.cos();
+        let y = (n + alt) * cos_lat * lon.sin();
+        let z = (n * (1.0 - e2) + alt) * sin_lat;
+
+        (
+            N::from_f64(x).unwrap(),
+            N::from_f64(y).unwrap(),
+            N::from_f64(z).unwrap(),
+        )
+    }
+
+    /// Compute the observer-relative look angles to `target`
+    ///
+    /// This gives the azimuth, elevation and range that an observer at
+    /// `self` would need to point at to see `target`, via the `ENU`-to-`AER`
+    /// conversion chain.
+    pub fn look_angles(&self, target: WGS84<N>) -> AER<N> {
+        let (ox, oy, oz) = self.to_ecef();
+        let (tx, ty, tz) = target.to_ecef();
+
+        let dx = f64::from(tx) - f64::from(ox);
+        let dy = f64::from(ty) - f64::from(oy);
+        let dz = f64::from(tz) - f64::from(oz);
+
+        let lat = f64::from(self.latitude);
+        let lon = f64::from(self.longitude);
+        let sin_lat = lat.sin();
+        let cos_lat = lat.cos();
+        let sin_lon = lon.sin();
+        let cos_lon = lon.cos();
+
+        let east = -sin_lon * dx + cos_lon * dy;
+        let north = -sin_lat * cos_lon * dx - sin_lat * sin_lon * dy + cos_lat * dz;
+        let up = cos_lat * cos_lon * dx + cos_lat * sin_lon * dy + sin_lat * dz;
+
+        let enu = ENU::new(
+            N::from_f64(east).unwrap(),
+            N::from_f64(north).unwrap(),
+            N::from_f64(up).unwrap(),
+        );
+
+        AER::from(enu)
+    }
+
+    /// Is `target` visible from `self`?
+    ///
+    /// This rejects `target` if its computed elevation is below the
+    /// `min_elevation` mask angle (in radians), or if the straight line
+    /// segment between the two positions passes through the WGS84
+    /// ellipsoid, i.e. the Earth itself is in the way.
+    pub fn is_visible(&self, target: WGS84<N>, min_elevation: N) -> bool {
+        let aer = self.look_angles(target);
+        if aer.elevation_radians() < min_elevation {
+            return false;
+        }
+
+        let (ox, oy, oz) = self.to_ecef();
+        let (tx, ty, tz) = target.to_ecef();
+
+        let b = WGS84_A * (1.0 - WGS84_F);
+        let scale = |x: N, y: N, z: N| -> (f64, f64, f64) {
+            (f64::from(x) / WGS84_A, f64::from(y) / WGS84_A, f64::from(z) / b)
+        };
+
+        let (osx, osy, osz) = scale(ox, oy, oz);
+        let (tsx, tsy, tsz) = scale(tx, ty, tz);
+
+        let dx = tsx - osx;
+        let dy = tsy - osy;
+        let dz = tsz - osz;
+
+        let dot_od = osx * dx + osy * dy + osz * dz;
+        let dot_dd = dx * dx + dy * dy + dz * dz;
+
+        if dot_dd == 0.0 {
+            return true;
+        }
+
+        // Vertex of the quadratic |observer + t*d|^2 in scaled space. If it
+        // falls outside the open segment (0, 1), the closest approach to the
+        // ellipsoid center happens at an endpoint, so the segment itself
+        // can't dip below the unit sphere.
+        let t_min = -dot_od / dot_dd;
+        if t_min <= 0.0 || t_min >= 1.0 {
+            return true;
+        }
+
+        let px = osx + t_min * dx;
+        let py = osy + t_min * dy;
+        let pz = osz + t_min * dz;
+        let min_sq = px * px + py * py + pz * pz;
+
+        min_sq >= 1.0
+    }
+
+    /// Encode this position as a DNS LOC (RFC 1876) resource record payload
+    ///
+    /// # Arguments
+    /// - `size_cm` diameter of a sphere enclosing the described entity, in centimeters
+    /// - `horiz_precision_cm` horizontal precision, in centimeters
+    /// - `vert_precision_cm` vertical precision, in centimeters
+    pub fn to_loc_wire(
+        &self,
+        size_cm: f64,
+        horiz_precision_cm: f64,
+        vert_precision_cm: f64,
+    ) -> [u8; 16] {
+        let lat_as = f64::from(self.latitude_degrees()) * 3_600_000.0;
+        let lon_as = f64::from(self.longitude_degrees()) * 3_600_000.0;
+        let alt_cm = f64::from(self.altitude) * 100.0;
+
+        let lat_bits = (lat_as + 2_147_483_648.0).round() as u32;
+        let lon_bits = (lon_as + 2_147_483_648.0).round() as u32;
+        let alt_bits = (alt_cm + 10_000_000.0).round() as u32;
+
+        let mut wire = [0u8; 16];
+        wire[0] = 0; // version
+        wire[1] = encode_loc_precision(size_cm);
+        wire[2] = encode_loc_precision(horiz_precision_cm);
+        wire[3] = encode_loc_precision(vert_precision_cm);
+        wire[4..8].copy_from_slice(&lat_bits.to_be_bytes());
+        wire[8..12].copy_from_slice(&lon_bits.to_be_bytes());
+        wire[12..16].copy_from_slice(&alt_bits.to_be_bytes());
+
+        wire
+    }
+
+    /// Decode a position from a DNS LOC (RFC 1876) resource record payload
+    ///
+    /// Returns `None` if the decoded latitude falls outside [-90, 90]
+    /// degrees, since the payload may come from an untrusted DNS response
+    /// and shouldn't be trusted to panic-free constructors.
+    pub fn from_loc_wire(wire: [u8; 16]) -> Option<WGS84<N>> {
+        let lat_bits = u32::from_be_bytes([wire[4], wire[5], wire[6], wire[7]]);
+        let lon_bits = u32::from_be_bytes([wire[8], wire[9], wire[10], wire[11]]);
+        let alt_bits = u32::from_be_bytes([wire[12], wire[13], wire[14], wire[15]]);
+
+        let lat_degrees = (f64::from(lat_bits) - 2_147_483_648.0) / 3_600_000.0;
+        let lon_degrees = (f64::from(lon_bits) - 2_147_483_648.0) / 3_600_000.0;
+        let alt_m = (f64::from(alt_bits) - 10_000_000.0) / 100.0;
+
+        if !(-90.0..=90.0).contains(&lat_degrees) {
+            return None;
+        }
+
+        Some(WGS84::from_degrees_and_meters(
+            N::from_f64(lat_degrees).unwrap(),
+            N::from_f64(lon_degrees).unwrap(),
+            N::from_f64(alt_m).unwrap(),
+        ))
+    }
+}
+
+/// Encode a centimeter value as the LOC record's mantissa*10^exponent byte:
+/// high nibble is the mantissa (1-9), low nibble is the power of ten
+fn encode_loc_precision(cm: f64) -> u8 {
+    let mut mantissa = cm.max(0.0).round() as u64;
+    let mut exponent = 0u8;
+    while mantissa > 9 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    ((mantissa as u8) << 4) | exponent
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use assert::close;
+
+    #[test]
+    fn look_angles_due_north_is_zero_azimuth() {
+        let observer = WGS84::from_degrees_and_meters(0.0, 0.0, 0.0);
+        let target = WGS84::from_degrees_and_meters(1.0, 0.0, 0.0);
+
+        let aer = observer.look_angles(target);
+        close(aer.azimuth_degrees(), 0.0, 0.1);
+    }
+
+    #[test]
+    fn antipodal_target_is_occulted() {
+        let observer = WGS84::from_degrees_and_meters(0.0, 0.0, 1000.0);
+        let target = WGS84::from_degrees_and_meters(0.0, 180.0, 1000.0);
+
+        assert!(!observer.is_visible(target, -90.0_f64.to_radians()));
+    }
+
+    #[test]
+    fn loc_wire_matches_rfc1876_worked_example() {
+        // "42 21 54 N 71 06 18 W -24m", size 1m, horiz-pre 10000m, vert-pre 10m,
+        // worked through RFC 1876's own encoding rules (section 2/3):
+        // LATIT  = 2^31 + (42*3600 + 21*60 + 54) * 1000 = 2299997648 = 0x89172DD0
+        // LONGIT = 2^31 - (71*3600 +  6*60 + 18) * 1000 = 1891505648 = 0x70BE15F0
+        // ALT    = -24m in cm (-2400) + 10_000_000               =    9997600 = 0x00988D20
+        let original = WGS84::from_degrees_and_meters(42.365, -71.105, -24.0);
+        let wire = original.to_loc_wire(100.0, 1_000_000.0, 1000.0);
+
+        assert_eq!(
+            wire,
+            [
+                0x00, // version
+                0x12, // size: 1 * 10^2 cm = 1m
+                0x16, // horiz pre: 1 * 10^6 cm = 10000m
+                0x13, // vert pre: 1 * 10^3 cm = 10m
+                0x89, 0x17, 0x2d, 0xd0, // latitude
+                0x70, 0xbe, 0x15, 0xf0, // longitude
+                0x00, 0x98, 0x8d, 0x20, // altitude
+            ]
+        );
+
+        let decoded = WGS84::from_loc_wire(wire).unwrap();
+        close(original.latitude_degrees(), decoded.latitude_degrees(), 0.0001);
+        close(original.longitude_degrees(), decoded.longitude_degrees(), 0.0001);
+        close(original.altitude(), decoded.altitude(), 0.01);
+    }
+
+    #[test]
+    fn from_loc_wire_rejects_out_of_range_latitude_without_panicking() {
+        // An all-0xff payload decodes to a ~596.5 degree latitude, which is
+        // well outside [-90, 90] and must not be fed to a panicking
+        // constructor: this could come straight from an untrusted DNS reply.
+        let wire = [0xff_u8; 16];
+        let decoded: Option<WGS84<f64>> = WGS84::from_loc_wire(wire);
+        assert_eq!(decoded, None);
+    }
+
+    #[test]
+    fn loc_wire_version_byte_is_zero() {
+        let position = WGS84::from_degrees_and_meters(0.0, 0.0, 0.0);
+        let wire = position.to_loc_wire(100.0, 1000.0, 300.0);
+        assert_eq!(wire[0], 0);
+    }
+
+    #[test]
+    fn low_elevation_target_is_rejected_by_mask() {
+        let observer = WGS84::from_degrees_and_meters(0.0, 0.0, 0.0);
+        let target = WGS84::from_degrees_and_meters(0.0, 1.0, 0.0);
+
+        assert!(!observer.is_visible(target, 89.0_f64.to_radians()));
+    }
+
+    #[test]
+    fn nearby_high_elevation_target_is_visible() {
+        let observer = WGS84::from_degrees_and_meters(0.0, 0.0, 0.0);
+        let target = WGS84::from_degrees_and_meters(0.0, 0.0, 1000.0);
+
+        assert!(observer.is_visible(target, 10.0_f64.to_radians()));
+    }
+}